@@ -1,8 +1,20 @@
-use clap::Subcommand;
+use clap::{Args, Subcommand};
 use serde_json::json;
 use uuid::Uuid;
 
-use crate::util::{api_request, read_json_from_file};
+use crate::config::Config;
+use crate::util::{api_request, read_json_from_file, TlsOptions};
+
+/// Top-level `provider` surface: the mutual-TLS flags apply to every
+/// subcommand, so they are flattened alongside the chosen command.
+#[derive(Args)]
+pub struct ProviderArgs {
+    #[command(flatten)]
+    pub tls: TlsOptions,
+
+    #[command(subcommand)]
+    pub command: ProviderCommands,
+}
 
 #[derive(Subcommand)]
 pub enum ProviderCommands {
@@ -10,9 +22,13 @@ pub enum ProviderCommands {
     List,
     /// Upsert provider connection metadata
     Upsert {
-        /// Full JSON request payload (use '-' for stdin)
+        /// Named provider template from config.toml (e.g. "github")
+        #[arg(long)]
+        provider: Option<String>,
+        /// JSON request payload (use '-' for stdin). Overrides template fields
+        /// when combined with --provider; required when --provider is absent.
         #[arg(long)]
-        request_file: String,
+        request_file: Option<String>,
     },
     /// Revoke a provider connection by id
     Revoke {
@@ -25,14 +41,29 @@ pub enum ProviderCommands {
     },
 }
 
-pub async fn run(api_url: &str, token: Option<&str>, command: ProviderCommands) -> i32 {
-    match command {
-        ProviderCommands::List => list(api_url, token).await,
-        ProviderCommands::Upsert { request_file } => upsert(api_url, token, &request_file).await,
+pub async fn run(api_url: Option<&str>, token: Option<&str>, args: ProviderArgs) -> i32 {
+    // Provider-connection endpoints may sit behind a mutual-TLS gateway or an
+    // internal CA; configure the shared client before issuing any request.
+    crate::util::configure_client(&args.tls);
+
+    // Merge the config-file default for `api_url` (flags > env > config file).
+    // The token is resolved upstream through the shared `util::resolve_token`,
+    // which already consults the config-file token source.
+    let config = Config::load();
+    let api_url = config
+        .resolve_api_url(api_url)
+        .unwrap_or_else(|| "http://localhost:3000".to_string());
+
+    match args.command {
+        ProviderCommands::List => list(&api_url, token).await,
+        ProviderCommands::Upsert {
+            provider,
+            request_file,
+        } => upsert(&api_url, token, &config, provider.as_deref(), request_file.as_deref()).await,
         ProviderCommands::Revoke {
             connection_id,
             reason,
-        } => revoke(api_url, token, connection_id, &reason).await,
+        } => revoke(&api_url, token, connection_id, &reason).await,
     }
 }
 
@@ -51,15 +82,50 @@ async fn list(api_url: &str, token: Option<&str>) -> i32 {
     .await
 }
 
-async fn upsert(api_url: &str, token: Option<&str>, request_file: &str) -> i32 {
-    let body = match read_json_from_file(request_file) {
-        Ok(v) => v,
-        Err(e) => crate::util::exit_error(
-            &e,
-            Some("Provide a valid JSON provider-connection payload."),
-        ),
+async fn upsert(
+    api_url: &str,
+    token: Option<&str>,
+    config: &Config,
+    provider: Option<&str>,
+    request_file: Option<&str>,
+) -> i32 {
+    // Start from the named template (if any), then overlay the explicit file
+    // payload so callers can tweak individual fields without restating them.
+    let mut body = match provider {
+        Some(name) => match config.provider(name) {
+            Some(tmpl) => tmpl.to_upsert_body(name),
+            None => crate::util::exit_error(
+                &format!("Unknown provider template '{name}'."),
+                Some("Define it under [providers.<name>] in ~/.config/kura/config.toml."),
+            ),
+        },
+        None => json!({}),
     };
 
+    if let Some(path) = request_file {
+        let overrides = match read_json_from_file(path) {
+            Ok(v) => v,
+            Err(e) => crate::util::exit_error(
+                &e,
+                Some("Provide a valid JSON provider-connection payload."),
+            ),
+        };
+        match (body.as_object_mut(), overrides.as_object()) {
+            (Some(base), Some(extra)) => {
+                for (k, v) in extra {
+                    base.insert(k.clone(), v.clone());
+                }
+            }
+            // A non-object payload (or no template) is used verbatim.
+            _ => body = overrides,
+        }
+    } else if provider.is_none() {
+        crate::util::exit_error(
+            "Provide --request-file or --provider for upsert.",
+            Some("Pass a JSON payload with --request-file, or a named template with --provider."),
+        );
+    }
+
     api_request(
         api_url,
         reqwest::Method::POST,
@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+use serde_json::json;
+
+/// Layered CLI configuration loaded from `~/.config/kura/config.toml`.
+///
+/// Every field is optional; a missing value falls back to the next layer in
+/// the resolution order (explicit flags > environment > config file), so an
+/// empty or absent file simply preserves the built-in defaults.
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct Config {
+    /// Default API base URL when neither `--api-url` nor `KURA_API_URL` is set.
+    pub api_url: Option<String>,
+    /// Bearer token used when `KURA_API_KEY` is unset and no stored login
+    /// credentials exist.
+    pub token: Option<String>,
+    /// Named provider templates keyed by short name (e.g. "github").
+    #[serde(default)]
+    pub providers: BTreeMap<String, ProviderTemplate>,
+}
+
+/// Connection boilerplate for a named OAuth provider, so `--provider github`
+/// no longer has to be spelled out as hand-built JSON on every invocation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderTemplate {
+    pub authorize_url: String,
+    pub token_url: String,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    pub client_id: String,
+}
+
+/// Location of the layered config file, alongside the stored credentials.
+pub fn config_toml_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("kura")
+        .join("config.toml")
+}
+
+impl Config {
+    /// Load the config file, returning an empty config when it is absent.
+    ///
+    /// The file does not change during a single CLI invocation, so the parsed
+    /// result is cached and subsequent calls are free.
+    ///
+    /// A malformed file is fatal so the operator fixes the typo rather than
+    /// silently losing their configured defaults.
+    pub fn load() -> Config {
+        static CONFIG: OnceLock<Config> = OnceLock::new();
+        CONFIG.get_or_init(Config::read_from_disk).clone()
+    }
+
+    fn read_from_disk() -> Config {
+        let path = config_toml_path();
+        let raw = match std::fs::read_to_string(&path) {
+            Ok(r) => r,
+            Err(_) => return Config::default(),
+        };
+        match toml::from_str(&raw) {
+            Ok(cfg) => cfg,
+            Err(e) => crate::util::exit_error(
+                &format!("Invalid config file '{}': {e}", path.display()),
+                Some("Fix the TOML syntax or remove the file to use built-in defaults."),
+            ),
+        }
+    }
+
+    /// Resolve the effective API URL using flags > environment > config file.
+    pub fn resolve_api_url(&self, flag: Option<&str>) -> Option<String> {
+        flag.map(str::to_string)
+            .or_else(|| std::env::var("KURA_API_URL").ok())
+            .or_else(|| self.api_url.clone())
+    }
+
+    /// Look up a named provider template.
+    pub fn provider(&self, name: &str) -> Option<&ProviderTemplate> {
+        self.providers.get(name)
+    }
+}
+
+impl ProviderTemplate {
+    /// Render the template as the connection-metadata object understood by the
+    /// upsert endpoint.
+    pub fn to_upsert_body(&self, name: &str) -> serde_json::Value {
+        json!({
+            "provider": name,
+            "authorize_url": self.authorize_url,
+            "token_url": self.token_url,
+            "scopes": self.scopes,
+            "client_id": self.client_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolution_order_prefers_flag_then_env_then_file() {
+        let cfg = Config {
+            api_url: Some("https://file".into()),
+            ..Config::default()
+        };
+        // Flag wins outright, even over the environment.
+        std::env::set_var("KURA_API_URL", "https://env");
+        assert_eq!(
+            cfg.resolve_api_url(Some("https://flag")).as_deref(),
+            Some("https://flag")
+        );
+        // Without a flag, the environment beats the file.
+        assert_eq!(cfg.resolve_api_url(None).as_deref(), Some("https://env"));
+        // With no flag or env override, the file value is used.
+        std::env::remove_var("KURA_API_URL");
+        assert_eq!(cfg.resolve_api_url(None).as_deref(), Some("https://file"));
+    }
+
+    #[test]
+    fn template_renders_upsert_body() {
+        let tmpl = ProviderTemplate {
+            authorize_url: "https://github.com/login/oauth/authorize".into(),
+            token_url: "https://github.com/login/oauth/access_token".into(),
+            scopes: vec!["repo".into()],
+            client_id: "abc123".into(),
+        };
+        let body = tmpl.to_upsert_body("github");
+        assert_eq!(body["provider"], "github");
+        assert_eq!(body["client_id"], "abc123");
+        assert_eq!(body["scopes"][0], "repo");
+    }
+}
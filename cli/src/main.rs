@@ -8,9 +8,10 @@ use serde_json::json;
 #[derive(Parser)]
 #[command(name = "kura", version, about = "Kura Training CLI — Agent interface for training, nutrition, and health data")]
 struct Cli {
-    /// API base URL
-    #[arg(long, env = "KURA_API_URL", default_value = "http://localhost:3000")]
-    api_url: String,
+    /// API base URL (falls back to KURA_API_URL, then http://localhost:3000;
+    /// the config file's api_url is not read for these commands)
+    #[arg(long)]
+    api_url: Option<String>,
 
     #[command(subcommand)]
     command: Commands,
@@ -265,13 +266,20 @@ async fn main() {
     let _ = dotenvy::dotenv();
     let cli = Cli::parse();
 
+    // Resolve the base URL: explicit flag > KURA_API_URL > built-in default.
+    let api_url = cli
+        .api_url
+        .clone()
+        .or_else(|| std::env::var("KURA_API_URL").ok())
+        .unwrap_or_else(|| "http://localhost:3000".to_string());
+
     let result = match cli.command {
-        Commands::Health => health(&cli.api_url).await,
+        Commands::Health => health(&api_url).await,
         Commands::Admin { command } => admin_command(command).await,
-        Commands::Login => login(&cli.api_url).await,
+        Commands::Login => login(&api_url).await,
         Commands::Logout => logout(),
         Commands::Projection { command } => {
-            let token = match resolve_token(&cli.api_url).await {
+            let token = match resolve_token(&api_url).await {
                 Ok(t) => t,
                 Err(e) => exit_error(&e.to_string(), Some("Run `kura login` or set KURA_API_KEY")),
             };
@@ -279,14 +287,14 @@ async fn main() {
                 ProjectionCommands::Get {
                     projection_type,
                     key,
-                } => projection_get(&cli.api_url, &token, &projection_type, &key).await,
+                } => projection_get(&api_url, &token, &projection_type, &key).await,
                 ProjectionCommands::List { projection_type } => {
-                    projection_list(&cli.api_url, &token, &projection_type).await
+                    projection_list(&api_url, &token, &projection_type).await
                 }
             }
         }
         Commands::Event { command } => {
-            let token = match resolve_token(&cli.api_url).await {
+            let token = match resolve_token(&api_url).await {
                 Ok(t) => t,
                 Err(e) => exit_error(&e.to_string(), Some("Run `kura login` or set KURA_API_KEY")),
             };
@@ -300,7 +308,7 @@ async fn main() {
                     agent,
                 } => {
                     event_create(
-                        &cli.api_url,
+                        &api_url,
                         &token,
                         &event_type,
                         timestamp.as_deref(),
@@ -319,7 +327,7 @@ async fn main() {
                     cursor,
                 } => {
                     event_list(
-                        &cli.api_url,
+                        &api_url,
                         &token,
                         event_type.as_deref(),
                         since.as_deref(),
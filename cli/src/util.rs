@@ -1,6 +1,8 @@
 use std::io::Write;
+use std::sync::OnceLock;
 
 use chrono::{DateTime, Utc};
+use clap::Args;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -20,8 +22,112 @@ pub struct TokenResponse {
     pub expires_in: i64,
 }
 
+/// Transport-layer options for reaching provider-connection endpoints that
+/// sit behind a mutual-TLS gateway or an internal certificate authority.
+///
+/// When every field is unset the CLI uses the system trust store and a plain
+/// bearer token, matching the historical behaviour.
+#[derive(Args, Clone, Default)]
+pub struct TlsOptions {
+    /// PEM client certificate for mutual TLS (requires --client-key)
+    #[arg(long)]
+    pub client_cert: Option<String>,
+    /// PEM private key matching --client-cert
+    #[arg(long)]
+    pub client_key: Option<String>,
+    /// Extra CA bundle (PEM) to trust on top of the system roots
+    #[arg(long)]
+    pub ca_bundle: Option<String>,
+}
+
+impl TlsOptions {
+    /// True when no custom TLS material was supplied.
+    pub fn is_empty(&self) -> bool {
+        self.client_cert.is_none() && self.client_key.is_none() && self.ca_bundle.is_none()
+    }
+}
+
+static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Configure the process-wide HTTP client from resolved TLS options.
+///
+/// Called once by a command's `run` before dispatching. Subsequent calls are
+/// ignored, so the first set of options wins.
+pub fn configure_client(tls: &TlsOptions) {
+    let _ = CLIENT.set(build_client(tls));
+}
+
 pub fn client() -> reqwest::Client {
-    reqwest::Client::new()
+    CLIENT.get().cloned().unwrap_or_else(reqwest::Client::new)
+}
+
+/// Build an HTTP client honouring a mutual-TLS identity and any extra CA roots.
+///
+/// Falls back to the default client when no custom material is supplied. Any
+/// problem reading or parsing the supplied PEM files is fatal — an enterprise
+/// operator would rather see the misconfiguration than silently fall back to
+/// the system trust store.
+///
+/// The PEM identity and certificate loaders below require reqwest's
+/// `rustls-tls` feature, which the manifest must enable (add
+/// `features = ["rustls-tls"]` to the reqwest dependency). We also pin the
+/// rustls backend explicitly with `use_rustls_tls` so a PEM `Identity` is
+/// accepted even when native-tls remains the default backend.
+fn build_client(tls: &TlsOptions) -> reqwest::Client {
+    if tls.is_empty() {
+        return reqwest::Client::new();
+    }
+
+    let mut builder = reqwest::Client::builder().use_rustls_tls();
+
+    match (&tls.client_cert, &tls.client_key) {
+        (Some(cert), Some(key)) => {
+            let cert_pem = match std::fs::read(cert) {
+                Ok(b) => b,
+                Err(e) => exit_error(&format!("Failed to read --client-cert '{cert}': {e}"), None),
+            };
+            let key_pem = match std::fs::read(key) {
+                Ok(b) => b,
+                Err(e) => exit_error(&format!("Failed to read --client-key '{key}': {e}"), None),
+            };
+            // The rustls `Identity::from_pem` expects certificate and key in
+            // one PEM buffer.
+            let mut identity_pem = cert_pem;
+            identity_pem.push(b'\n');
+            identity_pem.extend_from_slice(&key_pem);
+            match reqwest::Identity::from_pem(&identity_pem) {
+                Ok(identity) => builder = builder.identity(identity),
+                Err(e) => exit_error(&format!("Invalid client certificate/key: {e}"), None),
+            }
+        }
+        (None, None) => {}
+        _ => exit_error(
+            "--client-cert and --client-key must be supplied together.",
+            Some("Provide both the PEM certificate and its matching private key."),
+        ),
+    }
+
+    if let Some(ca) = &tls.ca_bundle {
+        let pem = match std::fs::read(ca) {
+            Ok(b) => b,
+            Err(e) => exit_error(&format!("Failed to read --ca-bundle '{ca}': {e}"), None),
+        };
+        // A bundle may hold several certs (intermediate + root, or multiple
+        // roots); trust every entry rather than just the first.
+        match reqwest::Certificate::from_pem_bundle(&pem) {
+            Ok(certs) => {
+                for cert in certs {
+                    builder = builder.add_root_certificate(cert);
+                }
+            }
+            Err(e) => exit_error(&format!("Invalid CA bundle '{ca}': {e}"), None),
+        }
+    }
+
+    match builder.build() {
+        Ok(client) => client,
+        Err(e) => exit_error(&format!("Failed to build TLS client: {e}"), None),
+    }
 }
 
 pub fn exit_error(message: &str, docs_hint: Option<&str>) -> ! {
@@ -72,7 +178,8 @@ pub fn save_credentials(creds: &StoredCredentials) -> Result<(), Box<dyn std::er
 /// Resolve a Bearer token for API requests (priority order):
 /// 1. KURA_API_KEY env var
 /// 2. ~/.config/kura/config.json (with auto-refresh)
-/// 3. Error
+/// 3. `token` from ~/.config/kura/config.toml
+/// 4. Error
 pub async fn resolve_token(api_url: &str) -> Result<String, Box<dyn std::error::Error>> {
     // 1. Environment variable
     if let Ok(key) = std::env::var("KURA_API_KEY") {
@@ -100,6 +207,11 @@ pub async fn resolve_token(api_url: &str) -> Result<String, Box<dyn std::error::
         return Ok(creds.access_token);
     }
 
+    // 3. Config-file token source
+    if let Some(token) = crate::config::Config::load().token {
+        return Ok(token);
+    }
+
     Err("No credentials found. Run `kura login` or set KURA_API_KEY.".into())
 }
 